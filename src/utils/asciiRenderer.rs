@@ -0,0 +1,40 @@
+// ASCII-превью DataMatrix: та же матрица модулей, что используют SVG/PNG-пути,
+// отрисованная блочными символами. Пригодится для CLI, CI-снепшотов и отладки
+// раскладки FNC1 без открытия картинки.
+export interface AsciiRenderOptions {
+  scale?: 1 | 2; // символов на модуль по горизонтали; по вертикали всегда 1 строка на модуль
+  inverted?: boolean; // true — светлые модули на тёмном терминале
+}
+
+const DARK = '█'; // FULL BLOCK
+const LIGHT = ' ';
+
+export function renderDataMatrixAscii(matrix: boolean[][], options: AsciiRenderOptions = {}): string {
+  const scale = options.scale ?? 1;
+  const inverted = options.inverted ?? false;
+
+  if (matrix.length === 0 || matrix[0].length === 0) {
+    throw new Error('renderDataMatrixAscii: пустая матрица модулей');
+  }
+
+  const darkChar = inverted ? LIGHT : DARK;
+  const lightChar = inverted ? DARK : LIGHT;
+  const quietZoneChar = lightChar.repeat(scale);
+
+  const rows = matrix.length;
+  const cols = matrix[0].length;
+  const quietRow = quietZoneChar.repeat(cols + 2);
+
+  const lines: string[] = [quietRow];
+  for (let y = 0; y < rows; y += 1) {
+    let line = quietZoneChar;
+    for (let x = 0; x < cols; x += 1) {
+      line += (matrix[y][x] ? darkChar : lightChar).repeat(scale);
+    }
+    line += quietZoneChar;
+    lines.push(line);
+  }
+  lines.push(quietRow);
+
+  return lines.join('\n');
+}
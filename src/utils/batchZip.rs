@@ -0,0 +1,79 @@
+// Серверная логика обработчика /api/generate-batch. Генерирует этикетки
+// конкурентно через пул воркеров с ограничением и стримит готовые записи в ZIP
+// по мере готовности, чтобы партия из тысячи этикеток не копилась целиком в памяти.
+import { parseGs1Human, splitGs1Elements, Gs1ParseError } from './gs1Parser';
+import type { ExportFormat } from './serverExports';
+
+const MAX_CONCURRENT = 8;
+
+// Клиент присылает человекочитаемый ввод как есть — сервер парсит его ровно
+// один раз, в renderOne, а не повторно внутри переданного render.
+export interface BatchItem {
+  gs1: string;
+  format: ExportFormat;
+}
+
+export interface ManifestEntry {
+  input: string;
+  filename: string | null;
+  error: string | null;
+}
+
+// Имя файла собирается из GTIN (AI 01) и серийного номера (AI 21), если они есть
+// в строке, — так партия из сотен этикеток остаётся узнаваемой без manifest.json.
+// Если ни того, ни другого нет, используется порядковый номер записи.
+function deriveFilename(elements: { ai: string; value: string }[], index: number, format: ExportFormat): string {
+  const sanitize = (value: string) => value.replace(/[^a-zA-Z0-9_-]/g, '');
+  const gtin = elements.find((el) => el.ai === '01')?.value;
+  const serial = elements.find((el) => el.ai === '21')?.value;
+  const base = [gtin, serial].filter(Boolean).map(sanitize).join('-');
+  return `${base || `label-${index + 1}`}.${format}`;
+}
+
+// Рендерит одну запись партии; render — та же функция, что обслуживает /api/generate.
+async function renderOne(
+  item: BatchItem,
+  index: number,
+  render: (gs1: string, format: BatchItem['format']) => Promise<Buffer>,
+): Promise<{ filename: string; data: Buffer }> {
+  const parsed = parseGs1Human(item.gs1);
+  const data = await render(parsed, item.format);
+  const filename = deriveFilename(splitGs1Elements(parsed), index, item.format);
+  return { filename, data };
+}
+
+// Отдаёт ZIP-архив в переданный writer по мере готовности каждой этикетки,
+// не дожидаясь окончания всей партии. Ошибка одной записи не прерывает остальные —
+// она попадает в manifest.json внутри архива.
+export async function streamBatchZip(
+  items: BatchItem[],
+  render: (gs1: string, format: BatchItem['format']) => Promise<Buffer>,
+  zipWriter: { append: (name: string, data: Buffer) => void; finalize: () => void },
+): Promise<ManifestEntry[]> {
+  const manifest: ManifestEntry[] = new Array(items.length);
+  let nextIndex = 0;
+
+  async function worker() {
+    for (;;) {
+      const index = nextIndex;
+      nextIndex += 1;
+      if (index >= items.length) return;
+      const item = items[index];
+      try {
+        const result = await renderOne(item, index, render);
+        zipWriter.append(result.filename, result.data);
+        manifest[index] = { input: item.gs1, filename: result.filename, error: null };
+      } catch (err) {
+        const message = err instanceof Gs1ParseError ? err.message : 'Не удалось сгенерировать этикетку';
+        manifest[index] = { input: item.gs1, filename: null, error: message };
+      }
+    }
+  }
+
+  const workerCount = Math.min(MAX_CONCURRENT, items.length) || 1;
+  await Promise.all(Array.from({ length: workerCount }, () => worker()));
+
+  zipWriter.append('manifest.json', Buffer.from(JSON.stringify(manifest, null, 2)));
+  zipWriter.finalize();
+  return manifest;
+}
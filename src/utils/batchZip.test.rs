@@ -0,0 +1,59 @@
+// Поведенческие проверки пакетной генерации: вид строки, приходящей в render,
+// деривация имени из GTIN/серийного номера и устойчивость manifest к гонке
+// в пуле воркеров. Самодостаточный скрипт без внешнего тестового раннера —
+// см. gs1Parser.test.rs.
+import assert from 'node:assert/strict';
+import { streamBatchZip } from './batchZip';
+
+const items = [
+  { gs1: '(01)01234567890128(21)AAA', format: 'png' as const },
+  { gs1: '(01)01234567890129(21)BAD', format: 'svg' as const }, // неверная контрольная цифра GTIN
+  { gs1: '(01)01234567890128(21)SN42', format: 'pdf' as const },
+];
+
+const appended: string[] = [];
+const zipWriter = {
+  append: (name: string) => {
+    appended.push(name);
+  },
+  finalize: () => {},
+};
+
+// render получает строку в скобочной форме AI без экранированного литерала "\x1D".
+// Для этих входов разобранная и исходная строки совпадают, так что число вызовов
+// parseGs1Human здесь не проверяется — только то, что сломанная запись до render не доходит.
+let renderCalls = 0;
+async function render(gs1Text: string): Promise<Buffer> {
+  renderCalls += 1;
+  assert.ok(gs1Text.startsWith('('), 'expected an already-parsed element string');
+  assert.ok(!gs1Text.includes('\\x1D'), 'FNC1 must be a real 0x1D byte, not an escaped literal');
+  // Первая запись финиширует последней, третья — первой: проверяет, что
+  // индекс в manifest берётся из позиции в items, а не из порядка завершения.
+  const delayMs = gs1Text.startsWith('(01)01234567890128(21)AAA') ? 15 : 1;
+  await new Promise((resolve) => setTimeout(resolve, delayMs));
+  return Buffer.from(gs1Text);
+}
+
+(async () => {
+  const manifest = await streamBatchZip(items, render, zipWriter);
+
+  assert.equal(manifest.length, 3);
+
+  assert.equal(manifest[0].input, items[0].gs1);
+  assert.equal(manifest[0].error, null);
+  assert.equal(manifest[0].filename, '01234567890128-AAA.png');
+
+  assert.equal(manifest[1].input, items[1].gs1);
+  assert.notEqual(manifest[1].error, null);
+  assert.equal(manifest[1].filename, null);
+
+  assert.equal(manifest[2].input, items[2].gs1);
+  assert.equal(manifest[2].error, null);
+  assert.equal(manifest[2].filename, '01234567890128-SN42.pdf');
+
+  // render вызывается только для валидных записей — сломанный GTIN не доходит до рендера.
+  assert.equal(renderCalls, 2);
+  assert.ok(appended.includes('manifest.json'));
+
+  console.log('batchZip.test.rs: ok');
+})();
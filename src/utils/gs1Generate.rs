@@ -0,0 +1,25 @@
+// Серверная логика, стоящая за /api/generate: единственное место, где
+// человекочитаемый ввод реально становится элемент-стрингой. Зеркалит то, как
+// streamBatchZip уже парсит каждый BatchItem на сервере — один вызов
+// parseGs1Human на запрос, независимо от того, что мог успеть проверить клиент.
+import { parseGs1Human } from './gs1Parser';
+
+export interface GenerateRequest {
+  gs1: string;
+  format: 'png' | 'pdf' | 'svg' | 'txt';
+  moduleSizeMm?: number;
+  quietZoneMm?: number;
+  asciiScale?: 1 | 2;
+  asciiInverted?: boolean;
+}
+
+// render — та же функция, что использует /api/generate-batch;
+// ей нужна уже готовая элемент-стринга, а не человекочитаемый ввод.
+export async function generateLabel(
+  request: GenerateRequest,
+  render: (gs1Text: string, request: GenerateRequest) => Promise<Buffer>,
+): Promise<{ gs1Text: string; data: Buffer }> {
+  const gs1Text = parseGs1Human(request.gs1);
+  const data = await render(gs1Text, request);
+  return { gs1Text, data };
+}
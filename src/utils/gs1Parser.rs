@@ -0,0 +1,157 @@
+// Парсер человекочитаемой GS1-строки вида "(01)01234567890128(17)250101(10)ABC123"
+// в элемент-стрингу с символами FNC1 (0x1D) на местах переменных разделителей.
+// Используется клиентом перед отправкой на /api/generate и должен быть зеркально
+// повторён на сервере, чтобы оба конца одинаково понимали разметку AI.
+const FNC1 = '\x1D';
+
+// Длина данных для AI: число — фиксированная длина, { max } — переменная длина до max символов.
+type AiLength = number | { max: number };
+
+// Таблица Application Identifier. Не претендует на полноту стандарта GS1 General
+// Specifications — перечислены только AI, которые реально встречаются в этом проекте.
+const AI_TABLE: Record<string, AiLength> = {
+  '00': 18,
+  '01': 14,
+  '02': 14,
+  '10': { max: 20 },
+  '11': 6,
+  '12': 6,
+  '13': 6,
+  '15': 6,
+  '16': 6,
+  '17': 6,
+  '20': 2,
+  '21': { max: 20 },
+  '22': { max: 20 },
+  '240': { max: 30 },
+  '241': { max: 30 },
+  '250': { max: 30 },
+  '30': { max: 8 },
+  '37': { max: 8 },
+  '90': { max: 30 },
+  '91': { max: 90 },
+};
+
+export class Gs1ParseError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'Gs1ParseError';
+  }
+}
+
+// Контрольная цифра GTIN (mod-10): справа налево чередуем веса 3 и 1.
+function gtinCheckDigit(digits: string): number {
+  let sum = 0;
+  let weight = 3;
+  for (let i = digits.length - 1; i >= 0; i -= 1) {
+    sum += Number(digits[i]) * weight;
+    weight = weight === 3 ? 1 : 3;
+  }
+  return (10 - (sum % 10)) % 10;
+}
+
+function validateGtin(ai: string, value: string) {
+  if (ai !== '00' && ai !== '01' && ai !== '02') return;
+  const withoutCheckDigit = value.slice(0, -1);
+  const expected = gtinCheckDigit(withoutCheckDigit);
+  const actual = Number(value[value.length - 1]);
+  if (actual !== expected) {
+    throw new Gs1ParseError(
+      `AI ${ai}: неверная контрольная цифра GTIN "${value}" (ожидалась ${expected}, получена ${actual})`,
+    );
+  }
+}
+
+// Разбирает строку "(AI)данные(AI)данные..." и возвращает элемент-стрингу с FNC1
+// после каждого переменного поля, кроме случая, когда оно последнее в строке.
+export function parseGs1Human(input: string): string {
+  const elements: { ai: string; value: string; variable: boolean }[] = [];
+  let pos = 0;
+
+  while (pos < input.length) {
+    if (input[pos] !== '(') {
+      throw new Gs1ParseError(`Ожидалась "(" на позиции ${pos}, найдено "${input[pos]}"`);
+    }
+    const close = input.indexOf(')', pos);
+    if (close === -1) {
+      throw new Gs1ParseError(`Не закрыта скобка AI, начатая на позиции ${pos}`);
+    }
+    const ai = input.slice(pos + 1, close);
+    const length = AI_TABLE[ai];
+    if (length === undefined) {
+      throw new Gs1ParseError(`Неизвестный Application Identifier "${ai}"`);
+    }
+
+    const dataStart = close + 1;
+    let value: string;
+    let variable: boolean;
+    if (typeof length === 'number') {
+      value = input.slice(dataStart, dataStart + length);
+      if (value.length !== length) {
+        throw new Gs1ParseError(
+          `AI ${ai}: ожидалась фиксированная длина ${length}, получено ${value.length} символов`,
+        );
+      }
+      variable = false;
+      pos = dataStart + length;
+    } else {
+      const nextParen = input.indexOf('(', dataStart);
+      const end = nextParen === -1 ? input.length : nextParen;
+      value = input.slice(dataStart, end);
+      if (value.length === 0 || value.length > length.max) {
+        throw new Gs1ParseError(
+          `AI ${ai}: длина данных должна быть от 1 до ${length.max}, получено ${value.length}`,
+        );
+      }
+      variable = true;
+      pos = end;
+    }
+
+    validateGtin(ai, value);
+    elements.push({ ai, value, variable });
+  }
+
+  if (elements.length === 0) {
+    throw new Gs1ParseError('Пустая GS1-строка');
+  }
+
+  return elements
+    .map((el, i) => {
+      const isLast = i === elements.length - 1;
+      const separator = el.variable && !isLast ? FNC1 : '';
+      return `(${el.ai})${el.value}${separator}`;
+    })
+    .join('');
+}
+
+export interface Gs1Element {
+  ai: string;
+  value: string;
+}
+
+// Обратная операция: разбирает уже готовую элемент-стрингу (с расставленными FNC1)
+// на пары AI/значение. Используется там, где на руках элемент-стринга, а не
+// человекочитаемый ввод — сравнение при верификации, вывод имени файла и т.п.
+export function splitGs1Elements(elementString: string): Gs1Element[] {
+  const elements: Gs1Element[] = [];
+  for (const chunk of elementString.split(FNC1)) {
+    let pos = 0;
+    while (pos < chunk.length) {
+      const close = chunk.indexOf(')', pos);
+      if (chunk[pos] !== '(' || close === -1) {
+        throw new Gs1ParseError(`Повреждённая элемент-стринга рядом с позицией ${pos}: "${chunk}"`);
+      }
+      const ai = chunk.slice(pos + 1, close);
+      const length = AI_TABLE[ai];
+      if (length === undefined) {
+        throw new Gs1ParseError(`Неизвестный Application Identifier "${ai}"`);
+      }
+      const dataStart = close + 1;
+      const end = typeof length === 'number' ? dataStart + length : chunk.length;
+      const value = chunk.slice(dataStart, end);
+      elements.push({ ai, value });
+      pos = end;
+    }
+  }
+  return elements;
+}
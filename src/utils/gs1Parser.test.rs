@@ -0,0 +1,39 @@
+// Поведенческие проверки парсера: контрольная цифра GTIN и расстановка FNC1.
+// В репозитории нет тестового раннера — файл самодостаточен: запускается как
+// обычный скрипт (`node -r ts-node/register src/utils/gs1Parser.test.rs`),
+// падает через assert при регрессии и печатает "ok" при успехе.
+import assert from 'node:assert/strict';
+import { parseGs1Human, splitGs1Elements, Gs1ParseError } from './gs1Parser';
+
+// Корректный GTIN (01)01234567890128 — контрольная цифра совпадает.
+assert.equal(parseGs1Human('(01)01234567890128'), '(01)01234567890128');
+
+// Неверная контрольная цифра отклоняется с понятной ошибкой.
+assert.throws(() => parseGs1Human('(01)01234567890129'), Gs1ParseError);
+
+// FNC1 ставится после переменного поля, только если оно не последнее в строке.
+assert.equal(
+  parseGs1Human('(01)01234567890128(10)ABC123(21)XYZ'),
+  '(01)01234567890128(10)ABC123\x1D(21)XYZ',
+);
+
+// Единственное переменное поле, если оно последнее, разделителя не получает.
+assert.equal(parseGs1Human('(10)ABC123'), '(10)ABC123');
+
+// Фиксированные поля разделителя не получают вообще никогда.
+assert.equal(parseGs1Human('(01)01234567890128(17)250101'), '(01)01234567890128(17)250101');
+
+// splitGs1Elements — обратная операция к parseGs1Human, включая FNC1-разбиение.
+assert.deepEqual(splitGs1Elements('(01)01234567890128(10)ABC123\x1D(21)XYZ'), [
+  { ai: '01', value: '01234567890128' },
+  { ai: '10', value: 'ABC123' },
+  { ai: '21', value: 'XYZ' },
+]);
+
+// Неизвестный AI — явная ошибка, а не тихое проглатывание.
+assert.throws(() => parseGs1Human('(99)value'), Gs1ParseError);
+
+// Фиксированная длина проверяется строго: короче ожидаемой — ошибка.
+assert.throws(() => parseGs1Human('(17)2501'), Gs1ParseError);
+
+console.log('gs1Parser.test.rs: ok');
@@ -0,0 +1,127 @@
+// Серверная логика, стоящая за /api/generate?verify=1 и /api/decode: декодирует
+// уже отрисованный символ DataMatrix обратно в элемент-стрингу, сверяет её с
+// исходным gs1 (round-trip) и считает базовые метрики качества печати.
+// Фактическое чтение модулей из картинки/фото — дело внешнего декодера ECC200
+// (decodeMatrix/decodeImageToGs1 передаются снаружи), здесь — только сверка и метрики.
+import { splitGs1Elements, Gs1ParseError } from './gs1Parser';
+
+export interface DecodedAiElement {
+  ai: string;
+  value: string;
+}
+
+// Детектированный размер символа — число модулей по каждой оси (например 24x24),
+// а не их произведение: произведение не различает квадратный 24x24 от
+// прямоугольного 16x36, которые интересуют интегратора по-разному.
+export interface ModuleDimensions {
+  rows: number;
+  cols: number;
+}
+
+export interface QualityMetrics {
+  moduleDimensions: ModuleDimensions;
+  quietZonePresent: boolean | null;
+}
+
+export interface DecodeReport {
+  success: boolean;
+  decodedGs1: string | null;
+  aiBreakdown: DecodedAiElement[];
+  moduleDimensions: ModuleDimensions | null;
+  quietZonePresent: boolean | null;
+  error?: string;
+}
+
+// Матрица, которую видит verifyRenderedSymbol, — это «голая» матрица модулей
+// кодировщика: квиет-зону добавляют svgRenderer/asciiRenderer только при
+// рендере, в этой матрице её нет. У валидного ECC200-символа сплошной финдер-
+// паттерн идёт по левому столбцу и нижней строке — они всегда тёмные, поэтому
+// проверка периметра на "светлоту" здесь ничего не детектирует и обязана
+// возвращать "неизвестно", а не ложное false.
+function computeEncoderMetrics(matrix: boolean[][]): QualityMetrics {
+  const rows = matrix.length;
+  const cols = rows > 0 ? matrix[0].length : 0;
+  return { moduleDimensions: { rows, cols }, quietZonePresent: null };
+}
+
+// Матрица из фото-декодера, напротив, захватывает область вокруг символа вместе
+// с квиет-зоной, так что периметр действительно можно проверить на отсутствие
+// тёмных модулей и, если зона найдена, вычесть её из итоговых размеров символа.
+function computePhotoMetrics(matrix: boolean[][]): QualityMetrics {
+  const rows = matrix.length;
+  const cols = rows > 0 ? matrix[0].length : 0;
+  let quietZonePresent = rows > 2 && cols > 2;
+  for (let x = 0; x < cols && quietZonePresent; x += 1) {
+    if (matrix[0][x] || matrix[rows - 1][x]) quietZonePresent = false;
+  }
+  for (let y = 0; y < rows && quietZonePresent; y += 1) {
+    if (matrix[y][0] || matrix[y][cols - 1]) quietZonePresent = false;
+  }
+  const moduleDimensions = quietZonePresent ? { rows: rows - 2, cols: cols - 2 } : { rows, cols };
+  return { moduleDimensions, quietZonePresent };
+}
+
+function buildDecodeReport(
+  decodedGs1: string | null,
+  expectedGs1: string | null,
+  metrics: QualityMetrics | null,
+): DecodeReport {
+  if (decodedGs1 === null) {
+    return {
+      success: false,
+      decodedGs1: null,
+      aiBreakdown: [],
+      moduleDimensions: metrics?.moduleDimensions ?? null,
+      quietZonePresent: metrics?.quietZonePresent ?? null,
+      error: 'Не удалось распознать символ',
+    };
+  }
+
+  let aiBreakdown: DecodedAiElement[] = [];
+  let parseError: string | undefined;
+  try {
+    aiBreakdown = splitGs1Elements(decodedGs1);
+  } catch (err) {
+    parseError = err instanceof Gs1ParseError ? err.message : 'Распознанная строка повреждена';
+  }
+
+  const matchesExpected = expectedGs1 === null || decodedGs1 === expectedGs1;
+  const success = matchesExpected && !parseError;
+
+  return {
+    success,
+    decodedGs1,
+    aiBreakdown,
+    moduleDimensions: metrics?.moduleDimensions ?? null,
+    quietZonePresent: metrics?.quietZonePresent ?? null,
+    error: success
+      ? undefined
+      : parseError ?? 'Распознанная строка не совпадает с запрошенным gs1',
+  };
+}
+
+// Используется /api/generate?verify=1 сразу после рендера: декодирует ту же
+// матрицу модулей, что ушла в SVG/PNG/PDF, и сверяет её с исходным gs1Text.
+// quietZonePresent в ответе всегда null — голая матрица кодировщика не несёт
+// этой информации, она появляется только на этапе рендера.
+export async function verifyRenderedSymbol(
+  expectedGs1: string,
+  matrix: boolean[][],
+  decodeMatrix: (matrix: boolean[][]) => Promise<string | null>,
+): Promise<DecodeReport> {
+  const metrics = computeEncoderMetrics(matrix);
+  const decodedGs1 = await decodeMatrix(matrix);
+  return buildDecodeReport(decodedGs1, expectedGs1, metrics);
+}
+
+// Используется /api/decode: вход — произвольное фото, ожидаемой строки нет,
+// поэтому success означает только "символ читается и разбирается на AI".
+export async function decodeImage(
+  imageBuffer: Buffer,
+  decodeImageToGs1: (image: Buffer) => Promise<{ gs1: string; matrix: boolean[][] } | null>,
+): Promise<DecodeReport> {
+  const decoded = await decodeImageToGs1(imageBuffer);
+  if (!decoded) return buildDecodeReport(null, null, null);
+  const metrics = computePhotoMetrics(decoded.matrix);
+  return buildDecodeReport(decoded.gs1, null, metrics);
+}
@@ -0,0 +1,57 @@
+// Поведенческие проверки round-trip верификации: совпадение/несовпадение
+// декодированной строки, размеры символа и присутствие квиет-зоны там, где
+// её вообще можно детектировать. Самодостаточный скрипт без внешнего
+// раннера — см. gs1Parser.test.rs.
+import assert from 'node:assert/strict';
+import { verifyRenderedSymbol, decodeImage } from './gs1Verify';
+
+const squareMatrix = Array.from({ length: 24 }, () => Array(24).fill(true));
+
+(async () => {
+  // Успешный round-trip: декодер вернул ровно тот же gs1, что и просили.
+  const ok = await verifyRenderedSymbol('(01)01234567890128', squareMatrix, async () => '(01)01234567890128');
+  assert.equal(ok.success, true);
+  assert.deepEqual(ok.aiBreakdown, [{ ai: '01', value: '01234567890128' }]);
+  // Голая матрица кодировщика не несёт информации о квиет-зоне — метрика не
+  // должна врать про false, только "неизвестно".
+  assert.equal(ok.quietZonePresent, null);
+  assert.deepEqual(ok.moduleDimensions, { rows: 24, cols: 24 });
+
+  // Декодированная строка отличается от запрошенной — это реальная регрессия рендера/FNC1.
+  const mismatch = await verifyRenderedSymbol(
+    '(01)01234567890128',
+    squareMatrix,
+    async () => '(01)01234567890128\x1D(21)EXTRA',
+  );
+  assert.equal(mismatch.success, false);
+  assert.ok(mismatch.error);
+
+  // Декодер не смог прочитать символ вообще.
+  const unreadable = await verifyRenderedSymbol('(01)01234567890128', squareMatrix, async () => null);
+  assert.equal(unreadable.success, false);
+  assert.equal(unreadable.decodedGs1, null);
+  assert.deepEqual(unreadable.moduleDimensions, { rows: 24, cols: 24 });
+
+  // Фото-путь: периметр светлый → квиет-зона найдена и вычтена из размеров символа.
+  const photoWithQuietZone = Array.from({ length: 26 }, (_, y) =>
+    Array.from({ length: 26 }, (_, x) => y > 0 && y < 25 && x > 0 && x < 25),
+  );
+  const photoDecoded = await decodeImage(Buffer.from(''), async () => ({
+    gs1: '(01)01234567890128',
+    matrix: photoWithQuietZone,
+  }));
+  assert.equal(photoDecoded.quietZonePresent, true);
+  assert.deepEqual(photoDecoded.moduleDimensions, { rows: 24, cols: 24 });
+
+  // Фото-путь без квиет-зоны (кадрирование её съело) — детектор должен это признать,
+  // а не притвориться, что зона на месте.
+  const photoCroppedTight = Array.from({ length: 24 }, () => Array(24).fill(true));
+  const photoNoQuietZone = await decodeImage(Buffer.from(''), async () => ({
+    gs1: '(01)01234567890128',
+    matrix: photoCroppedTight,
+  }));
+  assert.equal(photoNoQuietZone.quietZonePresent, false);
+  assert.deepEqual(photoNoQuietZone.moduleDimensions, { rows: 24, cols: 24 });
+
+  console.log('gs1Verify.test.rs: ok');
+})();
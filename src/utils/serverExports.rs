@@ -1,9 +1,41 @@
-// Клиентский helper (TypeScript). Вызывается из UI при клике "Экспорт PNG/PDF".
-// Передаёт gs1-строку (с символами 0x1D для разделителей) на сервер.
-export async function exportOnServer(gs1Text: string, format: 'png' | 'pdf' = 'png') {
-    // Убедитесь, что внутри gs1Text символ 0x1D стоит в нужных местах:
-    // Например: '(01)01234567890128' + '\x1D' + '(17)250101' + '\x1D' + '(10)ABC123'
-    const body = { format, gs1: gs1Text };
+// Клиентский helper (TypeScript). Вызывается из UI при клике "Экспорт PNG/PDF/SVG".
+// Принимает человекочитаемую форму "(01)01234567890128(17)250101(10)ABC123" и
+// шлёт её на сервер как есть — сервер (gs1Generate.rs/batchZip.rs) расставляет
+// FNC1 и валидирует AI/GTIN ровно один раз, так что прямой вызов /api/generate
+// в обход этого файла получает ту же проверку. parseGs1Human здесь вызывается
+// только как pre-flight: чтобы не тратить запрос впустую на заведомо битый ввод.
+import { parseGs1Human } from './gs1Parser';
+import type { DecodeReport } from './gs1Verify';
+
+export type ExportFormat = 'png' | 'pdf' | 'svg' | 'txt';
+
+// moduleSizeMm — размер одного модуля DataMatrix в миллиметрах (X-dimension).
+// quietZoneMm — ширина технического поля вокруг символа в миллиметрах.
+// Сервер строит SVG как единственный источник истины и растеризует его в PNG
+// либо встраивает в PDF, поэтому эти параметры одинаково влияют на все три формата.
+// asciiScale/asciiInverted влияют только на format: 'txt' — символов на модуль
+// и инверсию палитры для светлого текста на тёмном терминале.
+export interface ExportOptions {
+  moduleSizeMm?: number;
+  quietZoneMm?: number;
+  asciiScale?: 1 | 2;
+  asciiInverted?: boolean;
+}
+
+const EXTENSIONS: Record<ExportFormat, string> = {
+  png: 'png',
+  pdf: 'pdf',
+  svg: 'svg',
+  txt: 'txt',
+};
+
+export async function exportOnServer(
+  gs1Human: string,
+  format: ExportFormat = 'png',
+  options: ExportOptions = {},
+) {
+    parseGs1Human(gs1Human); // pre-flight: сервер — источник истины и парсит заново
+    const body = { format, gs1: gs1Human, ...options };
     const resp = await fetch('/api/generate', {
       method: 'POST',
       headers: { 'Content-Type': 'application/json' },
@@ -14,7 +46,7 @@ export async function exportOnServer(gs1Text: string, format: 'png' | 'pdf' = 'p
       throw new Error('Server export failed: ' + msg);
     }
     const blob = await resp.blob();
-    const filename = `gs1-dm.${format === 'png' ? 'png' : 'pdf'}`;
+    const filename = `gs1-dm.${EXTENSIONS[format]}`;
     // Скачивание файла в браузере
     const link = document.createElement('a');
     link.href = URL.createObjectURL(blob);
@@ -22,4 +54,75 @@ export async function exportOnServer(gs1Text: string, format: 'png' | 'pdf' = 'p
     document.body.appendChild(link);
     link.click();
     link.remove();
-  }
\ No newline at end of file
+  }
+
+// Пакетная печать: сотни этикеток за один HTTP-запрос вместо одного запроса на
+// этикетку. Сервер стримит ZIP по мере готовности записей, поэтому здесь просто
+// скачиваем итоговый blob целиком — промежуточных чанков на клиенте не видно.
+// Строки передаются в человекочитаемой форме как есть: парсинг в элемент-стрингу
+// с FNC1 делает сервер ровно один раз, чтобы не расставлять разделители дважды.
+export async function exportBatchOnServer(
+  gs1HumanList: string[],
+  format: ExportFormat = 'png',
+  options: ExportOptions = {},
+) {
+  const items = gs1HumanList.map((gs1Human) => ({ gs1: gs1Human, format }));
+  const body = { items, ...options };
+  const resp = await fetch('/api/generate-batch', {
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify(body),
+  });
+  if (!resp.ok) {
+    const msg = await resp.text();
+    throw new Error('Batch export failed: ' + msg);
+  }
+  const blob = await resp.blob();
+  const link = document.createElement('a');
+  link.href = URL.createObjectURL(blob);
+  link.download = 'gs1-dm-batch.zip';
+  document.body.appendChild(link);
+  link.click();
+  link.remove();
+}
+
+// Форма ответа (DecodeReport) и сама round-trip проверка с расчётом метрик
+// качества живут в gs1Verify.rs — здесь только тонкие fetch-обёртки для клиента.
+
+// Генерирует этикетку и сразу декодирует результат обратно, чтобы подтвердить,
+// что символ читается ровно как запрошенный gs1. Полезно для регрессионных
+// проверок рендера и раскладки FNC1 до того, как этикетка уйдёт на принтер.
+export async function verifyOnServer(
+  gs1Human: string,
+  format: ExportFormat = 'png',
+  options: ExportOptions = {},
+): Promise<DecodeReport> {
+  parseGs1Human(gs1Human); // pre-flight: сервер — источник истины и парсит заново
+  const body = { format, gs1: gs1Human, ...options };
+  const resp = await fetch('/api/generate?verify=1', {
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify(body),
+  });
+  if (!resp.ok) {
+    const msg = await resp.text();
+    throw new Error('Verify export failed: ' + msg);
+  }
+  return resp.json();
+}
+
+// Позволяет пользователю сфотографировать напечатанную этикетку и проверить,
+// что она сканируется в ожидаемый GS1-контент, не пересоздавая символ заново.
+export async function decodeOnServer(imageBlob: Blob): Promise<DecodeReport> {
+  const form = new FormData();
+  form.append('image', imageBlob);
+  const resp = await fetch('/api/decode', {
+    method: 'POST',
+    body: form,
+  });
+  if (!resp.ok) {
+    const msg = await resp.text();
+    throw new Error('Decode failed: ' + msg);
+  }
+  return resp.json();
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+// Построение SVG символа DataMatrix — единственный источник истины для всех
+// форматов экспорта. Сервер рендерит этот SVG, затем либо отдаёт его как есть
+// (format: 'svg'), либо растеризует в PNG, либо встраивает в PDF той же разметкой.
+// matrix — булева сетка модулей (true = тёмный модуль), как её возвращает кодировщик DataMatrix.
+export interface SvgRenderOptions {
+  moduleSizeMm?: number;
+  quietZoneMm?: number;
+}
+
+const DEFAULT_MODULE_SIZE_MM = 0.38; // минимальный X-dimension для печати по GS1
+const DEFAULT_QUIET_ZONE_MM = 1.0; // технический отступ в модулях GS1 эквивалентен ~1 мм при X=0.38
+
+export function renderDataMatrixSvg(matrix: boolean[][], options: SvgRenderOptions = {}): string {
+  const moduleSizeMm = options.moduleSizeMm ?? DEFAULT_MODULE_SIZE_MM;
+  const quietZoneMm = options.quietZoneMm ?? DEFAULT_QUIET_ZONE_MM;
+
+  if (matrix.length === 0 || matrix[0].length === 0) {
+    throw new Error('renderDataMatrixSvg: пустая матрица модулей');
+  }
+  const rows = matrix.length;
+  const cols = matrix[0].length;
+
+  // Размеры в миллиметрах — это то, что печатает принтер, независимо от его DPI.
+  const widthMm = cols * moduleSizeMm + quietZoneMm * 2;
+  const heightMm = rows * moduleSizeMm + quietZoneMm * 2;
+
+  const rects: string[] = [];
+  for (let y = 0; y < rows; y += 1) {
+    for (let x = 0; x < cols; x += 1) {
+      if (!matrix[y][x]) continue;
+      const rx = quietZoneMm + x * moduleSizeMm;
+      const ry = quietZoneMm + y * moduleSizeMm;
+      rects.push(`<rect x="${rx}" y="${ry}" width="${moduleSizeMm}" height="${moduleSizeMm}" />`);
+    }
+  }
+
+  return [
+    `<svg xmlns="http://www.w3.org/2000/svg" width="${widthMm}mm" height="${heightMm}mm" viewBox="0 0 ${widthMm} ${heightMm}">`,
+    `<rect x="0" y="0" width="${widthMm}" height="${heightMm}" fill="#fff" />`,
+    `<g fill="#000">${rects.join('')}</g>`,
+    `</svg>`,
+  ].join('');
+}